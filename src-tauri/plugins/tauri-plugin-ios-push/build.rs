@@ -1,4 +1,9 @@
-const COMMANDS: &[&str] = &["request_push_permission", "get_push_token"];
+const COMMANDS: &[&str] = &[
+    "request_push_permission",
+    "get_push_permission_state",
+    "get_push_token",
+    "register_push_listener",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS)
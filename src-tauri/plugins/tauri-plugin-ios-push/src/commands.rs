@@ -1,16 +1,41 @@
-use tauri::{command, AppHandle, Runtime};
+use tauri::{command, AppHandle, Runtime, Webview};
 
 use crate::models::*;
-use crate::{IosPushExt, Result};
+use crate::{authorize, PushExt, Result};
 
 #[command]
 pub(crate) fn request_push_permission<R: Runtime>(
     app: AppHandle<R>,
+    webview: Webview<R>,
+    options: Option<PushPermissionOptions>,
 ) -> Result<PermissionState> {
-    app.ios_push().request_push_permission()
+    authorize(&webview, "request_push_permission")?;
+    app.push().request_push_permission(options.unwrap_or_default())
 }
 
 #[command]
-pub(crate) fn get_push_token<R: Runtime>(app: AppHandle<R>) -> Result<String> {
-    app.ios_push().get_push_token()
+pub(crate) fn get_push_permission_state<R: Runtime>(
+    app: AppHandle<R>,
+    webview: Webview<R>,
+) -> Result<PermissionState> {
+    authorize(&webview, "get_push_permission_state")?;
+    app.push().get_push_permission_state()
+}
+
+#[command]
+pub(crate) fn get_push_token<R: Runtime>(
+    app: AppHandle<R>,
+    webview: Webview<R>,
+) -> Result<PushTokenResponse> {
+    authorize(&webview, "get_push_token")?;
+    app.push().get_push_token()
+}
+
+#[command]
+pub(crate) fn register_push_listener<R: Runtime>(
+    app: AppHandle<R>,
+    webview: Webview<R>,
+) -> Result<()> {
+    authorize(&webview, "register_push_listener")?;
+    app.push().register_push_listener()
 }
@@ -1,25 +1,44 @@
-use serde::de::DeserializeOwned;
 use tauri::{plugin::PluginApi, AppHandle, Runtime};
 
 use crate::models::*;
 use crate::Error;
 
-pub fn init<R: Runtime, C: DeserializeOwned>(
+pub fn init<R: Runtime>(
     app: &AppHandle<R>,
-    _api: PluginApi<R, C>,
-) -> crate::Result<IosPush<R>> {
-    Ok(IosPush(app.clone()))
+    _api: PluginApi<R, PushConfig>,
+) -> crate::Result<Push<R>> {
+    Ok(Push(app.clone()))
 }
 
 /// Desktop no-op implementation (kept for cross-platform compilation).
-pub struct IosPush<R: Runtime>(AppHandle<R>);
+pub struct Push<R: Runtime>(AppHandle<R>);
 
-impl<R: Runtime> IosPush<R> {
-    pub fn request_push_permission(&self) -> crate::Result<PermissionState> {
+impl<R: Runtime> Push<R> {
+    pub fn request_push_permission(
+        &self,
+        _options: PushPermissionOptions,
+    ) -> crate::Result<PermissionState> {
         Err(Error::UnsupportedPlatform)
     }
 
-    pub fn get_push_token(&self) -> crate::Result<String> {
+    pub fn get_push_permission_state(&self) -> crate::Result<PermissionState> {
+        Ok(PermissionState::Denied)
+    }
+
+    pub fn get_push_token(&self) -> crate::Result<PushTokenResponse> {
         Err(Error::UnsupportedPlatform)
     }
+
+    /// No-op on desktop; kept so cross-platform code can register a handler unconditionally.
+    pub fn on_notification<F>(&self, _handler: F) -> crate::Result<()>
+    where
+        F: Fn(NotificationEvent) + Send + Sync + 'static,
+    {
+        Ok(())
+    }
+
+    /// No-op on desktop; there is no native notification source to bridge.
+    pub fn register_push_listener(&self) -> crate::Result<()> {
+        Ok(())
+    }
 }
@@ -16,35 +16,74 @@ mod models;
 
 pub use error::{Error, Result};
 
+/// Managed allowlist controlling which remote origins may reach the plugin's IPC.
+struct RemoteDomainAccessList(Vec<RemoteDomainAccess>);
+
+/// Rejects the call unless the `webview`'s current origin is opted in for `command`.
+pub(crate) fn authorize<R: Runtime>(
+    webview: &tauri::Webview<R>,
+    command: &str,
+) -> Result<()> {
+    let access = webview.state::<RemoteDomainAccessList>();
+    let url = webview.url()?;
+    let label = webview.label();
+
+    if access
+        .0
+        .iter()
+        .any(|entry| entry.matches_origin(&url) && entry.allows(label, command))
+    {
+        return Ok(());
+    }
+
+    Err(Error::IpcAccessForbidden(command.to_string()))
+}
+
 #[cfg(desktop)]
-use desktop::IosPush;
+use desktop::Push;
 #[cfg(mobile)]
-use mobile::IosPush;
+use mobile::Push;
 
-/// Extensions to access iOS push APIs from app handles.
-pub trait IosPushExt<R: Runtime> {
-    fn ios_push(&self) -> &IosPush<R>;
+/// Extensions to access the cross-platform push APIs from app handles.
+pub trait PushExt<R: Runtime> {
+    fn push(&self) -> &Push<R>;
 }
 
-impl<R: Runtime, T: Manager<R>> IosPushExt<R> for T {
-    fn ios_push(&self) -> &IosPush<R> {
-        self.state::<IosPush<R>>().inner()
+impl<R: Runtime, T: Manager<R>> PushExt<R> for T {
+    fn push(&self) -> &Push<R> {
+        self.state::<Push<R>>().inner()
     }
 }
 
-/// Initializes the iOS push plugin.
+/// Initializes the push plugin (APNs on iOS, FCM on Android) with no origin opted in —
+/// every command is denied to every webview, whether it loads a local or a remote origin.
 pub fn init<R: Runtime>() -> TauriPlugin<R> {
-    Builder::new("ios-push")
+    init_with_access(Vec::new())
+}
+
+/// Initializes the push plugin, allowing the listed remote origins to reach the IPC.
+///
+/// Each [`RemoteDomainAccess`] entry opts a specific origin into a specific set of
+/// commands; anything not listed stays denied.
+pub fn init_with_access<R: Runtime>(access: Vec<RemoteDomainAccess>) -> TauriPlugin<R> {
+    Builder::<R, PushConfig>::new("ios-push")
         .invoke_handler(tauri::generate_handler![
             commands::request_push_permission,
-            commands::get_push_token
+            commands::get_push_permission_state,
+            commands::get_push_token,
+            commands::register_push_listener
         ])
-        .setup(|app, api| {
+        .setup(move |app, api| {
+            // Keep the typed config available to Rust; `register_{ios,android}_plugin`
+            // forwards the same config to the native plugin.
+            let config = api.config().clone();
             #[cfg(mobile)]
-            let ios_push = mobile::init(app, api)?;
+            let push = mobile::init(app, api)?;
             #[cfg(desktop)]
-            let ios_push = desktop::init(app, api)?;
-            app.manage(ios_push);
+            let push = desktop::init(app, api)?;
+            app.manage(push);
+            app.manage(config);
+            app.manage(RemoteDomainAccessList(access));
             Ok(())
         })
         .build()
@@ -1,7 +1,8 @@
-use serde::de::DeserializeOwned;
+use serde::Serialize;
 use tauri::{
+    ipc::Channel,
     plugin::{PluginApi, PluginHandle},
-    AppHandle, Runtime,
+    AppHandle, Emitter, Runtime,
 };
 
 use crate::models::*;
@@ -9,37 +10,91 @@ use crate::models::*;
 #[cfg(target_os = "ios")]
 tauri::ios_plugin_binding!(init_plugin_ios_push);
 
-pub fn init<R: Runtime, C: DeserializeOwned>(
-    _app: &AppHandle<R>,
-    api: PluginApi<R, C>,
-) -> crate::Result<IosPush<R>> {
+pub fn init<R: Runtime>(
+    app: &AppHandle<R>,
+    api: PluginApi<R, PushConfig>,
+) -> crate::Result<Push<R>> {
     #[cfg(target_os = "android")]
-    let handle = {
-        let handle = api.register_android_plugin("app.tauri.iospush", "IosPushPlugin")?;
-        handle
-    };
+    let handle = api.register_android_plugin("app.tauri.iospush", "PushPlugin")?;
     #[cfg(target_os = "ios")]
     let handle = api.register_ios_plugin(init_plugin_ios_push)?;
 
-    Ok(IosPush(handle))
+    Ok(Push {
+        app: app.clone(),
+        handle,
+    })
 }
 
-/// Access to the iOS push APIs.
-pub struct IosPush<R: Runtime>(PluginHandle<R>);
+#[derive(Serialize)]
+struct RegisterListenerArgs {
+    channel: Channel<NotificationEvent>,
+}
 
-impl<R: Runtime> IosPush<R> {
-    pub fn request_push_permission(&self) -> crate::Result<PermissionState> {
-        self.0
-            .run_mobile_plugin("requestPushPermission", ())
-            .map_err(Into::into)
+#[derive(serde::Deserialize)]
+struct PermissionResponse {
+    state: PermissionState,
+}
+
+/// Access to the cross-platform push APIs (APNs on iOS, FCM on Android).
+pub struct Push<R: Runtime> {
+    app: AppHandle<R>,
+    handle: PluginHandle<R>,
+}
+
+impl<R: Runtime> Push<R> {
+    pub fn request_push_permission(
+        &self,
+        options: PushPermissionOptions,
+    ) -> crate::Result<PermissionState> {
+        let response: PermissionResponse = self
+            .handle
+            .run_mobile_plugin("requestPushPermission", options)
+            .map_err(Into::into)?;
+
+        Ok(response.state)
     }
 
-    pub fn get_push_token(&self) -> crate::Result<String> {
-        let result: PushTokenResponse = self
-            .0
-            .run_mobile_plugin("getPushToken", ())
+    pub fn get_push_permission_state(&self) -> crate::Result<PermissionState> {
+        let response: PermissionResponse = self
+            .handle
+            .run_mobile_plugin("getPushPermissionState", ())
             .map_err(Into::into)?;
 
-        Ok(result.token)
+        Ok(response.state)
+    }
+
+    pub fn get_push_token(&self) -> crate::Result<PushTokenResponse> {
+        self.handle
+            .run_mobile_plugin("getPushToken", ())
+            .map_err(Into::into)
+    }
+
+    /// Registers `handler` to be invoked for every notification the native layer forwards.
+    ///
+    /// The handler runs on the channel's delivery thread, so it should return quickly.
+    pub fn on_notification<F>(&self, handler: F) -> crate::Result<()>
+    where
+        F: Fn(NotificationEvent) + Send + Sync + 'static,
+    {
+        let channel = Channel::new(move |message| {
+            let event: NotificationEvent = message.deserialize()?;
+            handler(event);
+            Ok(())
+        });
+
+        self.handle
+            .run_mobile_plugin("registerPushListener", RegisterListenerArgs { channel })
+            .map_err(Into::into)
+    }
+
+    /// Bridges native notifications to the `push://received` and `push://opened` app events.
+    pub fn register_push_listener(&self) -> crate::Result<()> {
+        let app = self.app.clone();
+        self.on_notification(move |event| {
+            let _ = match event {
+                NotificationEvent::Received(payload) => app.emit("push://received", payload),
+                NotificationEvent::Opened(payload) => app.emit("push://opened", payload),
+            };
+        })
     }
 }
@@ -0,0 +1,263 @@
+use serde::{Deserialize, Serialize};
+use tauri::Url;
+
+/// APNs environment named in [`PushConfig`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ApnsEnvironment {
+    /// Development sandbox (the default).
+    #[default]
+    Sandbox,
+    /// Production gateway.
+    Production,
+}
+
+/// Plugin configuration deserialized from the `ios-push` entry of the Tauri config.
+///
+/// Managed as app state and handed to the native plugin on `setup`. The iOS plugin parses
+/// it into its `config` property and reads no field of it afterwards; the Android plugin
+/// does not read it. None of the fields below changes how a token is registered or how a
+/// notification is presented.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PushConfig {
+    /// APNs environment (sandbox or production).
+    pub apns_environment: ApnsEnvironment,
+    /// APNs topic, i.e. the app's bundle id.
+    pub apns_topic: Option<String>,
+    /// Default notification sound name.
+    pub default_sound: Option<String>,
+    /// Firebase sender id.
+    pub fcm_sender_id: Option<String>,
+}
+
+/// Authorization state for push notifications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PermissionState {
+    /// The user granted permission.
+    Granted,
+    /// The user denied permission.
+    #[default]
+    Denied,
+    /// Permission has not been requested yet.
+    Prompt,
+}
+
+/// The push gateway a token is registered with.
+///
+/// Lets the hosted backend pick the right delivery service for a device token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PushProvider {
+    /// Apple Push Notification service (iOS).
+    Apns,
+    /// Firebase Cloud Messaging (Android).
+    Fcm,
+}
+
+/// Authorization options passed to `request_push_permission`.
+///
+/// `provisional` requests quiet authorization, which lets the app register a token
+/// without showing an up-front prompt — useful for registering silently on first launch.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PushPermissionOptions {
+    pub alert: bool,
+    pub badge: bool,
+    pub sound: bool,
+    pub provisional: bool,
+    pub critical_alert: bool,
+}
+
+impl Default for PushPermissionOptions {
+    fn default() -> Self {
+        Self {
+            alert: true,
+            badge: true,
+            sound: true,
+            provisional: false,
+            critical_alert: false,
+        }
+    }
+}
+
+/// Response returned by the native `getPushToken` call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PushTokenResponse {
+    pub token: String,
+    pub provider: PushProvider,
+}
+
+/// A single entry of the remote-domain IPC allowlist.
+///
+/// When the active window's origin matches [`domain`](Self::domain), only the listed
+/// [`commands`](Self::commands) are reachable from that origin's IPC. Everything is denied
+/// by default, so the hosted app must be explicitly opted in.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteDomainAccess {
+    /// Allowed origin, either a bare host (`os.ryo.lu`) or a full origin (`https://os.ryo.lu`).
+    pub domain: String,
+    /// Window labels the allowance applies to; empty means every window.
+    #[serde(default)]
+    pub windows: Vec<String>,
+    /// Plugin commands exposed to the matching origin.
+    #[serde(default)]
+    pub commands: Vec<String>,
+    /// Whether a leading `*.` wildcard in [`domain`](Self::domain) may match subdomains.
+    #[serde(default)]
+    pub allow_subdomains: bool,
+}
+
+impl RemoteDomainAccess {
+    /// Returns `true` when `url`'s scheme, host and port match this entry's domain.
+    pub(crate) fn matches_origin(&self, url: &Url) -> bool {
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+
+        // A `*.` prefix only matches subdomains when explicitly opted in; like the bare
+        // host form below, it implies `https` on the default port.
+        if let Some(suffix) = self.domain.strip_prefix("*.") {
+            return self.allow_subdomains
+                && url.scheme() == "https"
+                && url.port_or_known_default() == Some(443)
+                && (host == suffix || host.ends_with(&format!(".{suffix}")));
+        }
+
+        // Full origin form: compare scheme + host + port.
+        if let Ok(want) = Url::parse(&self.domain) {
+            return want.scheme() == url.scheme()
+                && want.host_str() == Some(host)
+                && want.port_or_known_default() == url.port_or_known_default();
+        }
+
+        // Bare host form implies `https` on the default port, so it still honors the
+        // scheme+host+port guarantee above instead of matching any scheme or port.
+        if let Ok(want) = Url::parse(&format!("https://{}", self.domain)) {
+            return want.scheme() == url.scheme()
+                && want.host_str() == Some(host)
+                && want.port_or_known_default() == url.port_or_known_default();
+        }
+
+        false
+    }
+
+    /// Returns `true` when `window` and `command` are both covered by this entry.
+    pub(crate) fn allows(&self, window: &str, command: &str) -> bool {
+        let window_ok = self.windows.is_empty() || self.windows.iter().any(|w| w == window);
+        window_ok && self.commands.iter().any(|c| c == command)
+    }
+}
+
+/// A notification event forwarded from the native layer over a [`tauri::ipc::Channel`].
+///
+/// The associated value is the notification payload serialized to JSON: the APNs
+/// `userInfo` dictionary on iOS, the FCM data map plus any `title`/`body` on Android.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "event", content = "payload")]
+pub enum NotificationEvent {
+    /// A notification was delivered without being tapped: presented while the app was in
+    /// the foreground, or handed to the app as a background push (iOS) or FCM message
+    /// (Android).
+    Received(serde_json::Value),
+    /// A notification was tapped, opening the app.
+    Opened(serde_json::Value),
+}
+
+/// A deep-link action carried by a notification payload.
+///
+/// Extracted from a `url` (or `deeplink`) key so a tapped notification can open a
+/// specific screen rather than just foregrounding the app.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationAction {
+    #[serde(alias = "deeplink")]
+    pub url: Option<String>,
+}
+
+impl NotificationAction {
+    /// Extracts a deep-link action from a raw notification payload, if one is present.
+    pub fn from_payload(payload: &serde_json::Value) -> Option<Self> {
+        serde_json::from_value::<Self>(payload.clone())
+            .ok()
+            .filter(|action| action.url.is_some())
+    }
+
+    /// Resolves the target against `allowed_origin`, returning it only when the resulting
+    /// origin matches — so a tapped notification can never open an arbitrary external page
+    /// inside the trusted window.
+    pub fn resolve(&self, allowed_origin: &Url) -> Option<Url> {
+        let target = allowed_origin.join(self.url.as_ref()?).ok()?;
+        (target.origin() == allowed_origin.origin()).then_some(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn access(domain: &str, allow_subdomains: bool) -> RemoteDomainAccess {
+        RemoteDomainAccess {
+            domain: domain.into(),
+            windows: vec![],
+            commands: vec![],
+            allow_subdomains,
+        }
+    }
+
+    #[test]
+    fn full_origin_requires_matching_scheme_and_port() {
+        let entry = access("https://os.ryo.lu", false);
+        assert!(entry.matches_origin(&Url::parse("https://os.ryo.lu").unwrap()));
+        assert!(!entry.matches_origin(&Url::parse("http://os.ryo.lu").unwrap()));
+        assert!(!entry.matches_origin(&Url::parse("https://os.ryo.lu:1234").unwrap()));
+    }
+
+    #[test]
+    fn bare_host_also_requires_matching_scheme_and_port() {
+        let entry = access("os.ryo.lu", false);
+        assert!(entry.matches_origin(&Url::parse("https://os.ryo.lu").unwrap()));
+        assert!(!entry.matches_origin(&Url::parse("http://os.ryo.lu").unwrap()));
+        assert!(!entry.matches_origin(&Url::parse("https://os.ryo.lu:1234").unwrap()));
+    }
+
+    #[test]
+    fn subdomain_wildcard_requires_opt_in() {
+        let denied = access("*.ryo.lu", false);
+        assert!(!denied.matches_origin(&Url::parse("https://os.ryo.lu").unwrap()));
+
+        let allowed = access("*.ryo.lu", true);
+        assert!(allowed.matches_origin(&Url::parse("https://os.ryo.lu").unwrap()));
+        assert!(allowed.matches_origin(&Url::parse("https://ryo.lu").unwrap()));
+        assert!(!allowed.matches_origin(&Url::parse("https://ryo.lu.evil.example").unwrap()));
+    }
+
+    #[test]
+    fn notification_action_resolves_same_origin_relative_path() {
+        let origin = Url::parse("https://os.ryo.lu").unwrap();
+        let action = NotificationAction {
+            url: Some("/inbox".into()),
+        };
+        let resolved = action.resolve(&origin).unwrap();
+        assert_eq!(resolved.as_str(), "https://os.ryo.lu/inbox");
+    }
+
+    #[test]
+    fn notification_action_rejects_off_origin_target() {
+        let origin = Url::parse("https://os.ryo.lu").unwrap();
+        let action = NotificationAction {
+            url: Some("https://evil.example/phish".into()),
+        };
+        assert!(action.resolve(&origin).is_none());
+    }
+
+    #[test]
+    fn notification_action_without_url_resolves_to_none() {
+        let origin = Url::parse("https://os.ryo.lu").unwrap();
+        let action = NotificationAction { url: None };
+        assert!(action.resolve(&origin).is_none());
+    }
+}
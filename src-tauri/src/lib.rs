@@ -1,16 +1,54 @@
-use tauri::{Manager, Url};
+use tauri::{Listener, Manager, Url};
+use tauri_plugin_ios_push::{NotificationAction, PushExt};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     // Always load the hosted app (https://os.ryo.lu) so the wrapper uses a stable origin
     let builder = tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
+        // The webview is navigated to a remote origin, so opt that origin into the push
+        // commands explicitly — everything else stays denied.
+        .plugin(tauri_plugin_ios_push::init_with_access(vec![
+            tauri_plugin_ios_push::RemoteDomainAccess {
+                domain: "https://os.ryo.lu".into(),
+                windows: vec!["main".into()],
+                commands: vec![
+                    "request_push_permission".into(),
+                    "get_push_permission_state".into(),
+                    "get_push_token".into(),
+                    "register_push_listener".into(),
+                ],
+                allow_subdomains: false,
+            },
+        ]))
         .setup(|app| {
+            let origin = Url::parse("https://os.ryo.lu")?;
             if let Some(window) = app.get_webview_window("main") {
-                let url = Url::parse("https://os.ryo.lu")?;
                 window.set_title("")?;
-                window.navigate(url)?;
+                window.navigate(origin.clone())?;
             }
+
+            // Start the native->event bridge ourselves so a notification tapped on cold
+            // launch still reaches `push://opened` before the webview has loaded and
+            // called `register_push_listener` itself.
+            app.push().register_push_listener()?;
+
+            // Route a tapped notification carrying a `url`/`deeplink` to the main webview,
+            // but only when the resolved target stays on the hosted origin.
+            let handle = app.handle().clone();
+            app.listen("push://opened", move |event| {
+                let Ok(payload) = serde_json::from_str::<serde_json::Value>(event.payload()) else {
+                    return;
+                };
+                let Some(target) =
+                    NotificationAction::from_payload(&payload).and_then(|a| a.resolve(&origin))
+                else {
+                    return;
+                };
+                if let Some(window) = handle.get_webview_window("main") {
+                    let _ = window.navigate(target);
+                }
+            });
             Ok(())
         });
 